@@ -3,12 +3,13 @@ use ctrlc;
 use env_logger;
 use hidapi::HidApi;
 use log::{debug, error, info, warn};
-use rusb::{DeviceHandle, UsbContext};
+use rusb::{Device, DeviceHandle, Hotplug, HotplugBuilder, UsbContext};
 use std::process::{Command, Stdio};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
-    Arc,
+    Arc, Mutex,
 };
+use std::thread;
 use std::time::Duration;
 use std::env;
 
@@ -110,7 +111,7 @@ impl ArctisController {
         Ok(())
     }
 
-    fn start(&self) -> Result<()> {
+    fn start(self: &Arc<Self>) -> Result<()> {
         loop {
             if !self.running.load(Ordering::SeqCst) {
                 return Ok(());
@@ -126,6 +127,94 @@ impl ArctisController {
             }
         }
 
+        if rusb::has_hotplug() {
+            if let Err(e) = self.run_with_hotplug() {
+                warn!("Hotplug subsystem failed ({}); falling back to polling", e);
+                self.run_with_polling();
+            }
+        } else {
+            info!("libusb hotplug not supported on this platform; falling back to polling");
+            self.run_with_polling();
+        }
+
+        Ok(())
+    }
+
+    // Event-driven path: register a libusb hotplug callback and let device_arrived /
+    // device_left drive setup, relinking and the read loop. Blocks until running is false
+    // (or the event thread dies), then joins every thread it spawned before returning so
+    // the caller's Arc drop deterministically triggers Drop::cleanup — we must not let
+    // the reader thread's own clone outlive the process.
+    fn run_with_hotplug(self: &Arc<Self>) -> Result<()> {
+        let usb_ctx = rusb::Context::new().context("Failed to initialize libusb context")?;
+
+        let active: Arc<Mutex<Option<ActiveConnection>>> = Arc::new(Mutex::new(None));
+
+        let handler: Box<dyn Hotplug<rusb::Context>> = Box::new(ArctisHotplug {
+            controller: self.clone(),
+            active: active.clone(),
+        });
+
+        let _registration = HotplugBuilder::new()
+            .vendor_id(VENDOR_ID)
+            .product_id(PRODUCT_ID)
+            .enumerate(true)
+            .register(&usb_ctx, handler)
+            .context("Failed to register libusb hotplug callback")?;
+
+        info!("Hotplug-based device detection active (instant reconnect)");
+
+        let running = self.running.clone();
+        let event_thread_failed = Arc::new(AtomicBool::new(false));
+        let failed_flag = event_thread_failed.clone();
+        let events_ctx = usb_ctx.clone();
+        let event_thread = thread::spawn(move || {
+            // A bounded timeout (rather than blocking forever on None) is what lets this
+            // loop notice `running` going false and return promptly on shutdown.
+            while running.load(Ordering::SeqCst) {
+                if let Err(e) = events_ctx.handle_events(Some(Duration::from_millis(200))) {
+                    warn!("libusb handle_events error: {:?}", e);
+                    failed_flag.store(true, Ordering::SeqCst);
+                    break;
+                }
+            }
+            debug!("Hotplug event thread exiting");
+        });
+
+        let mut event_thread_ok = true;
+        while self.running.load(Ordering::SeqCst) {
+            if event_thread_failed.load(Ordering::SeqCst) {
+                warn!("Hotplug event thread died; falling back to polling");
+                event_thread_ok = false;
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(200));
+        }
+
+        // Shut down any in-flight connection and the event thread ourselves, rather than
+        // leaving it to whichever clone of the controller Arc happens to drop last.
+        let conn = active.lock().unwrap().take();
+        if let Some(conn) = conn {
+            conn.active.store(false, Ordering::SeqCst);
+            if let Err(e) = conn.reader_thread.join() {
+                warn!("Reader thread panicked during shutdown: {:?}", e);
+            }
+        }
+
+        if let Err(e) = event_thread.join() {
+            warn!("Hotplug event thread panicked: {:?}", e);
+        }
+
+        if !event_thread_ok {
+            anyhow::bail!("Hotplug event thread failed");
+        }
+
+        Ok(())
+    }
+
+    // Fallback path for platforms without libusb hotplug support (e.g. Windows): keep
+    // polling usb_find_and_open and counting read errors in read_loop to detect detach.
+    fn run_with_polling(&self) {
         loop {
             if !self.running.load(Ordering::SeqCst) {
                 break;
@@ -147,8 +236,6 @@ impl ArctisController {
                 }
             }
         }
-
-        Ok(())
     }
 
     // Keep trying to open the USB device while running is true.
@@ -159,26 +246,11 @@ impl ArctisController {
         while self.running.load(Ordering::SeqCst) {
             match usb_find_and_open(&usb_ctx) {
                 Ok((mut handle, endpoint, interface_num)) => {
-                    info!("{}", "=".repeat(50));
-                    info!("Arctis 7+ ChatMix Enabled!");
-                    info!("  • Arctis_Game - for game audio");
-                    info!("  • Arctis_Chat - for chat/voice audio");
-                    info!("{}", "=".repeat(50));
-
-                    // Re-link virtual sinks to the freshly-attached physical device.
-                    if let Err(e) = self.relink_virtual_sinks_with_retry() {
-                        warn!("Failed to relink virtual sinks after reconnect: {}", e);
-                    }
-
-                    // Ensure all current streams are moved to Arctis_Game
-                    if let Err(e) = move_all_inputs_to("Arctis_Game") {
-                        warn!("Failed to move existing sink inputs to Arctis_Game: {}", e);
-                    } else {
-                        info!("Moved existing sink-inputs to Arctis_Game");
-                    }
+                    self.on_device_connected();
 
                     // Run the read loop. If it returns Err, propagate to allow reconnection attempts.
-                    let res = self.read_loop(&mut handle, endpoint);
+                    let conn_active = AtomicBool::new(true);
+                    let res = self.read_loop(&mut handle, endpoint, &conn_active);
 
                     // Try releasing the interface; ignore errors (device may already be gone).
                     if let Err(e) = handle.release_interface(interface_num) {
@@ -201,13 +273,42 @@ impl ArctisController {
         Ok(())
     }
 
+    // Shared connect sequence run once a device handle has just been claimed, whether by
+    // the polling path (try_connect_and_run) or the hotplug path (device_arrived): log the
+    // enabled banner, re-link the virtual sinks to the physical device, and move any
+    // existing sink-inputs onto Arctis_Game.
+    fn on_device_connected(&self) {
+        info!("{}", "=".repeat(50));
+        info!("Arctis 7+ ChatMix Enabled!");
+        info!("  • Arctis_Game - for game audio");
+        info!("  • Arctis_Chat - for chat/voice audio");
+        info!("{}", "=".repeat(50));
+
+        if let Err(e) = self.relink_virtual_sinks_with_retry() {
+            warn!("Failed to relink virtual sinks after reconnect: {}", e);
+        }
+
+        if let Err(e) = move_all_inputs_to("Arctis_Game") {
+            warn!("Failed to move existing sink inputs to Arctis_Game: {}", e);
+        } else {
+            info!("Moved existing sink-inputs to Arctis_Game");
+        }
+    }
+
     // Read HID reports and apply volumes. On repeated non-timeout errors or NoDevice, return Err.
-    fn read_loop<T: UsbContext>(&self, handle: &mut DeviceHandle<T>, endpoint: u8) -> Result<()> {
+    // `conn_active` lets a caller (e.g. the hotplug device_left handler) stop just this
+    // connection's reader without tearing down the whole controller.
+    fn read_loop<T: UsbContext>(
+        &self,
+        handle: &mut DeviceHandle<T>,
+        endpoint: u8,
+        conn_active: &AtomicBool,
+    ) -> Result<()> {
         let mut buf = [0u8; 64];
         let mut consecutive_errors = 0u32;
         const MAX_ERRORS: u32 = 5;
 
-        while self.running.load(Ordering::SeqCst) {
+        while self.running.load(Ordering::SeqCst) && conn_active.load(Ordering::SeqCst) {
             match handle.read_interrupt(endpoint, &mut buf, Duration::from_millis(1000)) {
                 Ok(len) => {
                     consecutive_errors = 0; // reset the error counter on success
@@ -328,12 +429,108 @@ impl Drop for ArctisController {
     }
 }
 
+// A single claimed-and-reading connection spawned by ArctisHotplug::device_arrived.
+// `active` is cleared by device_left, which flips it to false so the reader thread's
+// read_loop notices within one iteration, releases the interface itself, and returns.
+// `thread_id` lets the reader thread recognize and clear its own slot in
+// `ArctisHotplug::active` if it exits on its own (e.g. the MAX_ERRORS heuristic in
+// read_loop gives up on a still-attached but flaky device) without a device_left event
+// ever arriving to do it.
+struct ActiveConnection {
+    active: Arc<AtomicBool>,
+    reader_thread: thread::JoinHandle<()>,
+    thread_id: thread::ThreadId,
+}
+
+// libusb hotplug callback: reacts to attach/detach of the Arctis Nova 7 instead of
+// polling. Runs on the thread that calls `Context::handle_events`, so handlers must
+// not block for long; the actual HID read loop happens on its own spawned thread.
+// `active` is shared (not owned) so `run_with_hotplug` can also join the reader thread
+// itself on shutdown, rather than relying on it alone.
+struct ArctisHotplug {
+    controller: Arc<ArctisController>,
+    active: Arc<Mutex<Option<ActiveConnection>>>,
+}
+
+impl Hotplug<rusb::Context> for ArctisHotplug {
+    fn device_arrived(&mut self, device: Device<rusb::Context>) {
+        info!("Hotplug: Arctis Nova 7 arrived");
+
+        let mut slot = self.active.lock().unwrap();
+        if slot.is_some() {
+            debug!("Hotplug arrival event while already connected; ignoring");
+            return;
+        }
+
+        if let Err(e) = self.controller.setup_virtual_sinks() {
+            warn!("Setup failed on hotplug arrival: {}", e);
+        }
+
+        let (mut handle, endpoint, interface_num) = match open_and_claim(device) {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Failed to open/claim device on hotplug arrival: {}", e);
+                return;
+            }
+        };
+
+        self.controller.on_device_connected();
+
+        let active_slot = self.active.clone();
+        let active = Arc::new(AtomicBool::new(true));
+        let active_for_thread = active.clone();
+        let controller = self.controller.clone();
+        let reader_thread = thread::spawn(move || {
+            let res = controller.read_loop(&mut handle, endpoint, &active_for_thread);
+
+            if let Err(e) = handle.release_interface(interface_num) {
+                warn!("Failed to release interface (device may be gone): {:?}", e);
+            }
+
+            if let Err(e) = res {
+                debug!("Hotplug reader thread ended: {}", e);
+            }
+
+            // If we exited on our own (e.g. read_loop's MAX_ERRORS heuristic gave up on
+            // a still-attached but flaky device) rather than via device_left clearing us,
+            // clear our own slot so the next real device_arrived isn't blocked forever
+            // behind a dead connection.
+            let mut slot = active_slot.lock().unwrap();
+            if slot.as_ref().map(|c| c.thread_id) == Some(thread::current().id()) {
+                *slot = None;
+                debug!("Hotplug reader thread exited on its own; cleared active connection slot");
+            }
+        });
+
+        let thread_id = reader_thread.thread().id();
+        *slot = Some(ActiveConnection {
+            active,
+            reader_thread,
+            thread_id,
+        });
+    }
+
+    fn device_left(&mut self, _device: Device<rusb::Context>) {
+        info!("Hotplug: Arctis Nova 7 left");
+
+        let conn = self.active.lock().unwrap().take();
+        if let Some(conn) = conn {
+            conn.active.store(false, Ordering::SeqCst);
+            if let Err(e) = conn.reader_thread.join() {
+                warn!("Reader thread panicked during shutdown: {:?}", e);
+            }
+        } else {
+            debug!("Hotplug left event with no active connection; ignoring");
+        }
+    }
+}
+
 fn main() -> Result<()> {
     env_logger::init();
 
     info!("Initializing Arctis 7+ ChatMix...");
 
-    let controller = ArctisController::new()?;
+    let controller = Arc::new(ArctisController::new()?);
     controller.start()?;
 
     Ok(())
@@ -533,11 +730,10 @@ fn hidapi_send_sidetone(percent: u8) -> Result<()> {
 
 /* ---------- end hidapi sidetone ---------- */
 
-// Return (handle, endpoint_addr, interface_number)
-// This version tries to enable libusb auto-detach, falls back to manual detach,
-// and retries claiming the interface a few times to handle the kernel re-attaching quickly.
-fn usb_find_and_open<T: UsbContext>(usb_ctx: &T) -> Result<(DeviceHandle<T>, u8, u8)> {
-    let dev = usb_ctx
+// Enumerate the bus looking for the Arctis Nova 7. Used by the polling fallback path;
+// the hotplug path gets its Device directly from device_arrived instead.
+fn find_device<T: UsbContext>(usb_ctx: &T) -> Result<Device<T>> {
+    usb_ctx
         .devices()?
         .iter()
         .find(|d| {
@@ -547,8 +743,21 @@ fn usb_find_and_open<T: UsbContext>(usb_ctx: &T) -> Result<(DeviceHandle<T>, u8,
                 false
             }
         })
-        .ok_or_else(|| anyhow::anyhow!("Arctis Nova 7 not found. Please ensure it is connected."))?;
+        .ok_or_else(|| anyhow::anyhow!("Arctis Nova 7 not found. Please ensure it is connected."))
+}
+
+// Return (handle, endpoint_addr, interface_number)
+// This version tries to enable libusb auto-detach, falls back to manual detach,
+// and retries claiming the interface a few times to handle the kernel re-attaching quickly.
+fn usb_find_and_open<T: UsbContext>(usb_ctx: &T) -> Result<(DeviceHandle<T>, u8, u8)> {
+    let dev = find_device(usb_ctx)?;
+    open_and_claim(dev)
+}
 
+// Open and claim the HID interface on an already-located device. Shared by the polling
+// path (via usb_find_and_open) and the hotplug path (device_arrived hands us the Device
+// straight from the arrival event, so no re-enumeration is needed).
+fn open_and_claim<T: UsbContext>(dev: Device<T>) -> Result<(DeviceHandle<T>, u8, u8)> {
     info!("Found Arctis Nova 7 device");
 
     let config = dev.config_descriptor(0)?;